@@ -6,13 +6,14 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Terminal,
 };
-use reqwest::Client;
 use serde_json::json;
 use std::{
     env,
     io::{self, Write},
 };
 use std::fs::OpenOptions;
+use std::path::PathBuf;
+use std::process::Command;
 use crossterm::{
     event::{self, Event, KeyCode, KeyEvent},
     execute,
@@ -25,6 +26,61 @@ struct Project {
     name: String,
     description: String,
     web_url: String,
+    ssh_url: String,
+    http_url: String,
+    full_path: String,
+}
+
+// An open merge request on a project.
+#[derive(Debug, Clone)]
+struct MergeRequest {
+    title: String,
+    author: String,
+    state: String,
+}
+
+// A recent pipeline run on a project.
+#[derive(Debug, Clone)]
+struct Pipeline {
+    status: String,
+    ref_name: String,
+    created_at: String,
+}
+
+// An open issue on a project.
+#[derive(Debug, Clone)]
+struct Issue {
+    title: String,
+    author: String,
+    state: String,
+}
+
+// Which pane `render_ui` shows below the project list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum View {
+    Projects,
+    MergeRequests,
+    Pipelines,
+    Issues,
+}
+
+impl View {
+    fn next(self) -> Self {
+        match self {
+            View::Projects => View::MergeRequests,
+            View::MergeRequests => View::Pipelines,
+            View::Pipelines => View::Issues,
+            View::Issues => View::MergeRequests,
+        }
+    }
+}
+
+// Credentials/client needed to lazily fetch drill-down data for the
+// currently selected project.
+struct GitlabContext {
+    client: reqwest::Client,
+    host: String,
+    token: String,
 }
 
 // Application state
@@ -32,24 +88,193 @@ struct AppState {
     projects: Vec<Project>,
     selected_index: usize,
     list_state: ListState,
+    search_mode: bool,
+    search_query: String,
+    filtered: Vec<usize>,
+    view: View,
+    merge_requests_cache: std::collections::HashMap<String, Vec<MergeRequest>>,
+    pipelines_cache: std::collections::HashMap<String, Vec<Pipeline>>,
+    issues_cache: std::collections::HashMap<String, Vec<Issue>>,
+    backend_name: String,
+}
+
+impl AppState {
+    fn new(projects: Vec<Project>, backend_name: String) -> Self {
+        let filtered = (0..projects.len()).collect();
+        Self {
+            projects,
+            selected_index: 0,
+            list_state: ListState::default(),
+            search_mode: false,
+            search_query: String::new(),
+            filtered,
+            view: View::Projects,
+            merge_requests_cache: std::collections::HashMap::new(),
+            pipelines_cache: std::collections::HashMap::new(),
+            issues_cache: std::collections::HashMap::new(),
+            backend_name,
+        }
+    }
+
+    // Recompute `filtered` from `search_query` using a subsequence fuzzy match,
+    // sorted by descending score. An empty query matches everything in order.
+    fn refresh_filter(&mut self) {
+        if self.search_query.is_empty() {
+            self.filtered = (0..self.projects.len()).collect();
+        } else {
+            let query = self.search_query.to_lowercase();
+            let mut scored: Vec<(usize, i32)> = self
+                .projects
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, project)| {
+                    fuzzy_match_score(&project.name, &query).map(|score| (idx, score))
+                })
+                .collect();
+            scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+            self.filtered = scored.into_iter().map(|(idx, _)| idx).collect();
+        }
+
+        self.selected_index = 0;
+        if self.filtered.is_empty() {
+            self.list_state.select(None);
+        } else {
+            self.list_state.select(Some(0));
+        }
+    }
+
+    fn selected_project(&self) -> Option<&Project> {
+        self.filtered
+            .get(self.selected_index)
+            .and_then(|&idx| self.projects.get(idx))
+    }
 }
 
+// Subsequence fuzzy match of `query` (already lowercased) against `candidate`.
+// Returns None if not every query char is found in order, otherwise a score
+// that rewards consecutive matches and start-of-word matches.
+fn fuzzy_match_score(candidate: &str, query: &str) -> Option<i32> {
+    let candidate_lower = candidate.to_lowercase();
+    let candidate_chars: Vec<char> = candidate_lower.chars().collect();
+    let mut query_chars = query.chars().peekable();
+
+    let mut score = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (i, &ch) in candidate_chars.iter().enumerate() {
+        let Some(&target) = query_chars.peek() else {
+            break;
+        };
+        if ch == target {
+            query_chars.next();
+            match last_match {
+                Some(prev) if prev + 1 == i => score += 2,
+                _ => {}
+            }
+            let at_word_boundary = i == 0
+                || !candidate_chars[i - 1].is_alphanumeric();
+            if at_word_boundary {
+                score += 3;
+            }
+            last_match = Some(i);
+        } else {
+            // Penalize every skipped char, not just ones between two matches,
+            // so a match buried deep in a long candidate (e.g. "...-tool")
+            // doesn't tie with the same match right at the start of a short one.
+            score -= 1;
+        }
+    }
+
+    if query_chars.peek().is_some() {
+        None
+    } else {
+        Some(score)
+    }
+}
+
+// Positions in `candidate` (lowercased) that the fuzzy matcher matched against
+// `query`, for highlighting in the list.
+fn fuzzy_match_positions(candidate: &str, query: &str) -> Vec<usize> {
+    let candidate_lower = candidate.to_lowercase();
+    let candidate_chars: Vec<char> = candidate_lower.chars().collect();
+    let mut query_chars = query.chars().peekable();
+    let mut positions = Vec::new();
+
+    for (i, &ch) in candidate_chars.iter().enumerate() {
+        let Some(&target) = query_chars.peek() else {
+            break;
+        };
+        if ch == target {
+            query_chars.next();
+            positions.push(i);
+        }
+    }
+
+    positions
+}
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv().ok();
 
-    // Prompt for GitLab token
-    let gitlab_token = get_gitlab_token().unwrap_or_else(|| {
-        eprintln!("GitLab token is required. Exiting.");
-        std::process::exit(1);
-    });
+    // Select which backend to browse, and build the matching provider plus
+    // (for GitLab) the context used for lazy drill-down fetches
+    let provider_kind = get_provider_kind();
+    let (provider, gitlab_ctx): (Box<dyn Provider>, Option<GitlabContext>) = match provider_kind.as_str() {
+        "github" => {
+            let github_token = get_github_token().unwrap_or_else(|| {
+                eprintln!("GitHub token is required. Exiting.");
+                std::process::exit(1);
+            });
+            let github_owner = get_github_owner().unwrap_or_else(|| {
+                eprintln!("GitHub owner is required. Exiting.");
+                std::process::exit(1);
+            });
+            (
+                Box::new(GithubProvider { token: github_token, owner: github_owner }),
+                None,
+            )
+        }
+        _ => {
+            let gitlab_token = get_gitlab_token().unwrap_or_else(|| {
+                eprintln!("GitLab token is required. Exiting.");
+                std::process::exit(1);
+            });
+            let gitlab_host = get_gitlab_host().unwrap_or_else(|| {
+                eprintln!("GitLab host is required. Exiting.");
+                std::process::exit(1);
+            });
+            let gitlab_ssl_cert = get_gitlab_ssl_cert();
+            let gitlab_insecure = get_gitlab_insecure();
 
-    // Prompt for GitLab host
-    let gitlab_host = get_gitlab_host().unwrap_or_else(|| {
-        eprintln!("GitLab host is required. Exiting.");
-        std::process::exit(1);
-    });
+            let ctx = GitlabContext {
+                client: build_gitlab_client(&gitlab_ssl_cert, gitlab_insecure)?,
+                host: gitlab_host.clone(),
+                token: gitlab_token.clone(),
+            };
+            (
+                Box::new(GitlabProvider {
+                    token: gitlab_token,
+                    host: gitlab_host,
+                    ssl_cert: gitlab_ssl_cert,
+                    insecure: gitlab_insecure,
+                }),
+                Some(ctx),
+            )
+        }
+    };
+
+    // Single shared async runtime for the lifetime of the program
+    let runtime = tokio::runtime::Runtime::new()?;
 
+    // Fetch projects from the active provider before entering the alternate
+    // screen/raw mode, so its "Loaded N projects..." progress lines print to
+    // the normal scrollback instead of staircasing across a raw-mode alt
+    // screen that gets wiped by the first `terminal.draw()`.
+    let backend_name = provider.name().to_string();
+    let projects = provider.fetch_projects(&runtime).unwrap_or_else(|err| {
+        eprintln!("Failed to fetch projects: {}", err);
+        vec![]
+    });
 
     // Setup terminal
     enable_raw_mode()?;
@@ -58,21 +283,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Fetch projects from GitLab
-    let projects = fetch_projects(gitlab_token, gitlab_host).unwrap_or_else(|err| {
-        eprintln!("Failed to fetch projects: {}", err);
-        vec![]
-    });
-
-    let mut app_state = AppState {
-        projects,
-        selected_index: 0,
-        list_state: ListState::default(),
-    };
+    let mut app_state = AppState::new(projects, backend_name);
     app_state.list_state.select(Some(0));
 
     // Main event loop
-    let res = run_app(&mut terminal, &mut app_state);
+    let res = run_app(&mut terminal, &mut app_state, &runtime, gitlab_ctx.as_ref());
 
     // Restore terminal state
     disable_raw_mode()?;
@@ -87,31 +302,84 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 // Run the main application loop
-fn run_app<B: Backend>(
+fn run_app<B: Backend + std::io::Write>(
     terminal: &mut Terminal<B>,
     app_state: &mut AppState,
+    runtime: &tokio::runtime::Runtime,
+    gitlab_ctx: Option<&GitlabContext>,
 ) -> io::Result<()> {
     loop {
-        terminal.draw(|frame| render_ui::<B>(frame, app_state))?;
+        terminal.draw(|frame| render_ui(frame, app_state))?;
 
         if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+            if app_state.search_mode {
+                match code {
+                    KeyCode::Esc => {
+                        app_state.search_mode = false;
+                        app_state.search_query.clear();
+                        app_state.refresh_filter();
+                    }
+                    KeyCode::Enter => {
+                        app_state.search_mode = false;
+                    }
+                    KeyCode::Backspace => {
+                        app_state.search_query.pop();
+                        app_state.refresh_filter();
+                    }
+                    KeyCode::Char(c) => {
+                        app_state.search_query.push(c);
+                        app_state.refresh_filter();
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
             match code {
                 KeyCode::Esc => {
-                    //quit the program
-                    return Ok(());
+                    if app_state.view != View::Projects {
+                        app_state.view = View::Projects;
+                    } else {
+                        //quit the program
+                        return Ok(());
+                    }
                 },
                 KeyCode::Char('q') => return Ok(()),
-                KeyCode::Down => {
-                    if app_state.selected_index < app_state.projects.len() - 1 {
-                        app_state.selected_index += 1;
-                        app_state.list_state.select(Some(app_state.selected_index));
+                KeyCode::Char('/') => {
+                    app_state.search_mode = true;
+                }
+                KeyCode::Char('c') => {
+                    if let Some(project) = app_state.selected_project().cloned() {
+                        if let Err(err) = clone_and_open_shell(terminal, &project) {
+                            eprintln!("Failed to clone/open shell: {}", err);
+                        }
                     }
                 }
-                KeyCode::Up => {
-                    if app_state.selected_index > 0 {
-                        app_state.selected_index -= 1;
-                        app_state.list_state.select(Some(app_state.selected_index));
+                // Drill-down views are backed by GitLab-specific GraphQL
+                // queries, so there's nothing to show for other providers.
+                KeyCode::Enter if gitlab_ctx.is_some() => {
+                    if app_state.view == View::Projects {
+                        app_state.view = View::MergeRequests;
                     }
+                    ensure_drilldown_data(app_state, runtime, gitlab_ctx);
+                }
+                KeyCode::Tab if gitlab_ctx.is_some() => {
+                    app_state.view = if app_state.view == View::Projects {
+                        View::MergeRequests
+                    } else {
+                        app_state.view.next()
+                    };
+                    ensure_drilldown_data(app_state, runtime, gitlab_ctx);
+                }
+                KeyCode::Down if app_state.selected_index + 1 < app_state.filtered.len() => {
+                    app_state.selected_index += 1;
+                    app_state.list_state.select(Some(app_state.selected_index));
+                    ensure_drilldown_data(app_state, runtime, gitlab_ctx);
+                }
+                KeyCode::Up if app_state.selected_index > 0 => {
+                    app_state.selected_index -= 1;
+                    app_state.list_state.select(Some(app_state.selected_index));
+                    ensure_drilldown_data(app_state, runtime, gitlab_ctx);
                 }
                 _ => {}
             }
@@ -119,8 +387,125 @@ fn run_app<B: Backend>(
     }
 }
 
+// Base directory projects are cloned into, configurable via `GITLAB_CLONE_DIR`
+// (defaults to `./clones`).
+fn clone_base_dir() -> PathBuf {
+    PathBuf::from(env::var("GITLAB_CLONE_DIR").unwrap_or_else(|_| "clones".to_string()))
+}
+
+// Clone `project` (if not already present) into the configured base directory,
+// then suspend the TUI and drop the user into a `$SHELL` session inside the
+// working copy. The TUI is restored once the subshell exits.
+fn clone_and_open_shell<B: Backend + std::io::Write>(
+    terminal: &mut Terminal<B>,
+    project: &Project,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let base_dir = clone_base_dir();
+    std::fs::create_dir_all(&base_dir)?;
+    // Key the clone destination on `full_path`, not the bare `name`: two
+    // projects with the same name in different groups/owners would otherwise
+    // collide on one directory, and `dest.exists()` below would silently drop
+    // the user into whichever repo got cloned there first.
+    let dest = base_dir.join(project.full_path.replace('/', "__"));
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+
+    // Run the clone + subshell, but restore raw mode/alt screen no matter how
+    // this turns out so a failed clone or missing `$SHELL` never leaves the
+    // user's real terminal in a corrupted state.
+    let result = (|| -> Result<(), Box<dyn std::error::Error>> {
+        let clone_url = if !project.ssh_url.is_empty() {
+            &project.ssh_url
+        } else {
+            &project.http_url
+        };
+
+        if !dest.exists() {
+            println!("Cloning {} into {}...", project.name, dest.display());
+            let status = Command::new("git")
+                .arg("clone")
+                .arg(clone_url)
+                .arg(&dest)
+                .status()?;
+            if !status.success() {
+                return Err(format!("git clone exited with status {}", status).into());
+            }
+        }
+
+        let shell = env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_string());
+        Command::new(shell).current_dir(&dest).status()?;
+
+        Ok(())
+    })();
+
+    enable_raw_mode()?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+    terminal.clear()?;
+
+    result
+}
+
+// Lazily fetch and cache the data backing the currently active drill-down
+// view for the selected project, so re-entering a view doesn't refetch.
+fn ensure_drilldown_data(
+    app_state: &mut AppState,
+    runtime: &tokio::runtime::Runtime,
+    ctx: Option<&GitlabContext>,
+) {
+    let Some(ctx) = ctx else {
+        return;
+    };
+    let Some(full_path) = app_state.selected_project().map(|p| p.full_path.clone()) else {
+        return;
+    };
+    if full_path.is_empty() {
+        return;
+    }
+
+    match app_state.view {
+        View::Projects => {}
+        View::MergeRequests => {
+            if let std::collections::hash_map::Entry::Vacant(entry) =
+                app_state.merge_requests_cache.entry(full_path)
+            {
+                match fetch_merge_requests(runtime, ctx, entry.key()) {
+                    Ok(mrs) => {
+                        entry.insert(mrs);
+                    }
+                    Err(err) => eprintln!("Failed to fetch merge requests: {}", err),
+                }
+            }
+        }
+        View::Pipelines => {
+            if let std::collections::hash_map::Entry::Vacant(entry) =
+                app_state.pipelines_cache.entry(full_path)
+            {
+                match fetch_pipelines(runtime, ctx, entry.key()) {
+                    Ok(pipelines) => {
+                        entry.insert(pipelines);
+                    }
+                    Err(err) => eprintln!("Failed to fetch pipelines: {}", err),
+                }
+            }
+        }
+        View::Issues => {
+            if let std::collections::hash_map::Entry::Vacant(entry) =
+                app_state.issues_cache.entry(full_path)
+            {
+                match fetch_issues(runtime, ctx, entry.key()) {
+                    Ok(issues) => {
+                        entry.insert(issues);
+                    }
+                    Err(err) => eprintln!("Failed to fetch issues: {}", err),
+                }
+            }
+        }
+    }
+}
+
 // Render the terminal UI
-fn render_ui<B: Backend>(frame: &mut ratatui::Frame, app_state: &mut AppState) {
+fn render_ui(frame: &mut ratatui::Frame, app_state: &mut AppState) {
     let size = frame.area();
 
     // Split the layout into two sections: list and details
@@ -129,22 +514,73 @@ fn render_ui<B: Backend>(frame: &mut ratatui::Frame, app_state: &mut AppState) {
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
         .split(size);
 
-    // List of projects
+    // List of projects, filtered by the current search query (if any)
+    let query = app_state.search_query.to_lowercase();
     let items: Vec<ListItem> = app_state
-        .projects
+        .filtered
         .iter()
-        .map(|p| ListItem::new(p.name.clone()))
+        .filter_map(|&idx| app_state.projects.get(idx))
+        .map(|p| {
+            if query.is_empty() {
+                ListItem::new(p.name.clone())
+            } else {
+                let matched: std::collections::HashSet<usize> =
+                    fuzzy_match_positions(&p.name, &query).into_iter().collect();
+                let spans: Vec<ratatui::text::Span> = p
+                    .name
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        if matched.contains(&i) {
+                            ratatui::text::Span::styled(
+                                c.to_string(),
+                                Style::default().fg(Color::Yellow),
+                            )
+                        } else {
+                            ratatui::text::Span::raw(c.to_string())
+                        }
+                    })
+                    .collect();
+                ListItem::new(ratatui::text::Line::from(spans))
+            }
+        })
         .collect();
 
+    let title = if app_state.search_mode {
+        format!(
+            "{} Projects\\Esc to cancel | search: {}_",
+            app_state.backend_name, app_state.search_query
+        )
+    } else if !app_state.search_query.is_empty() {
+        format!(
+            "{} Projects\\Esc to quit | filter: {}",
+            app_state.backend_name, app_state.search_query
+        )
+    } else {
+        format!(
+            "{} Projects\\Esc to quit | / to search | Enter/Tab to drill down",
+            app_state.backend_name
+        )
+    };
+
     let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("Projects\\Esc to quit"))
+        .block(Block::default().borders(Borders::ALL).title(title))
         .highlight_style(Style::default().bg(Color::Blue))
         .highlight_symbol("> ");
 
     frame.render_stateful_widget(list, chunks[0], &mut app_state.list_state);
 
-    // Selected project details
-    if let Some(project) = app_state.projects.get(app_state.selected_index) {
+    // Bottom pane: project details, or the active drill-down view
+    match app_state.view {
+        View::Projects => render_details_pane(frame, app_state, chunks[1]),
+        View::MergeRequests => render_merge_requests_pane(frame, app_state, chunks[1]),
+        View::Pipelines => render_pipelines_pane(frame, app_state, chunks[1]),
+        View::Issues => render_issues_pane(frame, app_state, chunks[1]),
+    }
+}
+
+fn render_details_pane(frame: &mut ratatui::Frame, app_state: &AppState, area: ratatui::layout::Rect) {
+    if let Some(project) = app_state.selected_project() {
         let details = format!(
             "Name: {}\nDescription: {}\nWeb URL: {}",
             project.name,
@@ -153,70 +589,453 @@ fn render_ui<B: Backend>(frame: &mut ratatui::Frame, app_state: &mut AppState) {
         );
         let paragraph = Paragraph::new(details)
             .block(Block::default().borders(Borders::ALL).title("Details"));
-        frame.render_widget(paragraph, chunks[1]);
+        frame.render_widget(paragraph, area);
     }
 }
 
-// Fetch projects using the GitLab GraphQL API
+fn render_merge_requests_pane(frame: &mut ratatui::Frame, app_state: &AppState, area: ratatui::layout::Rect) {
+    let full_path = app_state.selected_project().map(|p| p.full_path.as_str()).unwrap_or("");
+    let items: Vec<ListItem> = app_state
+        .merge_requests_cache
+        .get(full_path)
+        .map(|mrs| {
+            mrs.iter()
+                .map(|mr| ListItem::new(format!("[{}] {} ({})", mr.state, mr.title, mr.author)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Merge Requests (Tab: next view, Esc: back)"),
+    );
+    frame.render_widget(list, area);
+}
+
+fn render_pipelines_pane(frame: &mut ratatui::Frame, app_state: &AppState, area: ratatui::layout::Rect) {
+    let full_path = app_state.selected_project().map(|p| p.full_path.as_str()).unwrap_or("");
+    let items: Vec<ListItem> = app_state
+        .pipelines_cache
+        .get(full_path)
+        .map(|pipelines| {
+            pipelines
+                .iter()
+                .map(|p| {
+                    let color = match p.status.to_lowercase().as_str() {
+                        "success" => Color::Green,
+                        "failed" => Color::Red,
+                        "running" | "pending" => Color::Yellow,
+                        _ => Color::White,
+                    };
+                    ListItem::new(format!("{} - {} ({})", p.status, p.ref_name, p.created_at))
+                        .style(Style::default().fg(color))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Pipelines (Tab: next view, Esc: back)"),
+    );
+    frame.render_widget(list, area);
+}
+
+fn render_issues_pane(frame: &mut ratatui::Frame, app_state: &AppState, area: ratatui::layout::Rect) {
+    let full_path = app_state.selected_project().map(|p| p.full_path.as_str()).unwrap_or("");
+    let items: Vec<ListItem> = app_state
+        .issues_cache
+        .get(full_path)
+        .map(|issues| {
+            issues
+                .iter()
+                .map(|issue| ListItem::new(format!("[{}] {} ({})", issue.state, issue.title, issue.author)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Issues (Tab: next view, Esc: back)"),
+    );
+    frame.render_widget(list, area);
+}
+
+// Page size used for each projects GraphQL request.
+const PROJECTS_PAGE_SIZE: i64 = 100;
+// Overall cap on the number of projects fetched, to bound memory/time on
+// instances with an enormous number of projects.
+const PROJECTS_FETCH_CAP: usize = 5000;
+
+// Exponential backoff parameters for retrying transient GraphQL failures.
+const RETRY_INITIAL_BACKOFF_MS: u64 = 250;
+const RETRY_MAX_BACKOFF_MS: u64 = 30_000;
+const RETRY_MAX_ATTEMPTS: u32 = 8;
+
+// A small pseudo-random jitter fraction in [0.0, 1.0), derived from the
+// system clock so we don't need to pull in a `rand` dependency just for
+// backoff jitter.
+fn jitter_fraction() -> f64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos % 1_000) as f64 / 1_000.0
+}
+
+// POST a GraphQL request with exponential backoff retry on connection errors
+// and on HTTP 429/500/502/503, honoring a `Retry-After` header given in
+// seconds (an HTTP-date value is ignored and the normal backoff is used).
+// Gives up after `RETRY_MAX_ATTEMPTS` attempts with a descriptive error.
+async fn post_graphql_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+    token: &str,
+    payload: &serde_json::Value,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let mut backoff_ms = RETRY_INITIAL_BACKOFF_MS;
+
+    for attempt in 1..=RETRY_MAX_ATTEMPTS {
+        let result = client.post(url).bearer_auth(token).json(payload).send().await;
+
+        let response = match result {
+            Ok(response) => response,
+            Err(err) => {
+                if attempt == RETRY_MAX_ATTEMPTS {
+                    return Err(format!(
+                        "GraphQL request failed after {} attempts: {}",
+                        RETRY_MAX_ATTEMPTS, err
+                    )
+                    .into());
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(backoff_ms)).await;
+                backoff_ms = (backoff_ms * 2).min(RETRY_MAX_BACKOFF_MS);
+                continue;
+            }
+        };
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json::<serde_json::Value>().await?);
+        }
+
+        let retryable = matches!(status.as_u16(), 429 | 500 | 502 | 503);
+        if !retryable || attempt == RETRY_MAX_ATTEMPTS {
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!(
+                "GraphQL request failed with status {} after {} attempt(s): {}",
+                status, attempt, body
+            )
+            .into());
+        }
+
+        let retry_after_ms = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok())
+            .map(|secs| secs * 1_000);
+
+        let delay_ms = retry_after_ms.unwrap_or_else(|| {
+            let jitter = (backoff_ms as f64 * jitter_fraction()) as u64;
+            backoff_ms + jitter
+        });
+
+        tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+        backoff_ms = (backoff_ms * 2).min(RETRY_MAX_BACKOFF_MS);
+    }
+
+    Err(format!("GraphQL request failed after {} attempts", RETRY_MAX_ATTEMPTS).into())
+}
+
+// Build the `reqwest::Client` used for all GitLab GraphQL calls, applying an
+// optional custom CA certificate and/or the "skip verification" toggle.
+fn build_gitlab_client(
+    gitlab_ssl_cert: &Option<String>,
+    gitlab_insecure: bool,
+) -> Result<reqwest::Client, Box<dyn std::error::Error>> {
+    let mut client_builder = reqwest::ClientBuilder::new();
+    if let Some(cert_path) = gitlab_ssl_cert {
+        let cert_bytes = std::fs::read(cert_path)?;
+        let cert = reqwest::Certificate::from_pem(&cert_bytes)?;
+        client_builder = client_builder.add_root_certificate(cert);
+    }
+    if gitlab_insecure {
+        client_builder = client_builder.danger_accept_invalid_certs(true);
+    }
+    Ok(client_builder.build()?)
+}
+
 fn fetch_projects(
+    runtime: &tokio::runtime::Runtime,
     gitlab_token: String,
     gitlab_host: String,
+    gitlab_ssl_cert: Option<String>,
+    gitlab_insecure: bool,
 ) -> Result<Vec<Project>, Box<dyn std::error::Error>> {
     let query = r#"
-        query Projects($first: Int) {
-            projects(first: $first) {
+        query Projects($first: Int, $after: String) {
+            projects(first: $first, after: $after) {
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                }
                 nodes {
                     id
                     name
                     description
                     webUrl
+                    sshUrlToRepo
+                    httpUrlToRepo
+                    fullPath
+                }
+            }
+        }
+    "#;
+
+    let client = build_gitlab_client(&gitlab_ssl_cert, gitlab_insecure)?;
+    let url = format!("https://{}/api/graphql", gitlab_host);
+
+    let mut projects = Vec::new();
+    let mut after: Option<String> = None;
+
+    loop {
+        let variables = json!({ "first": PROJECTS_PAGE_SIZE, "after": after });
+        let payload = json!({
+            "query": query,
+            "variables": variables,
+        });
+
+        let response = runtime.block_on(post_graphql_with_retry(
+            &client,
+            &url,
+            &gitlab_token,
+            &payload,
+        ))?;
+
+        let projects_data = response.get("data").and_then(|data| data.get("projects"));
+
+        if let Some(nodes) = projects_data
+            .and_then(|projects| projects.get("nodes"))
+            .and_then(|nodes| nodes.as_array())
+        {
+            for node in nodes {
+                let name = node.get("name").and_then(|n| n.as_str()).unwrap_or("N/A").to_string();
+                let description = node
+                    .get("description")
+                    .and_then(|d| d.as_str())
+                    .unwrap_or("No description")
+                    .to_string();
+                let web_url = node.get("webUrl").and_then(|w| w.as_str()).unwrap_or("N/A").to_string();
+                let ssh_url = node
+                    .get("sshUrlToRepo")
+                    .and_then(|u| u.as_str())
+                    .unwrap_or("")
+                    .to_string();
+                let http_url = node
+                    .get("httpUrlToRepo")
+                    .and_then(|u| u.as_str())
+                    .unwrap_or("")
+                    .to_string();
+                let full_path = node
+                    .get("fullPath")
+                    .and_then(|p| p.as_str())
+                    .unwrap_or("")
+                    .to_string();
+                projects.push(Project {
+                    name,
+                    description,
+                    web_url,
+                    ssh_url,
+                    http_url,
+                    full_path,
+                });
+            }
+        }
+
+        println!("Loaded {} projects...", projects.len());
+
+        if projects.len() >= PROJECTS_FETCH_CAP {
+            println!("Reached fetch cap of {} projects, stopping.", PROJECTS_FETCH_CAP);
+            break;
+        }
+
+        let page_info = projects_data.and_then(|projects| projects.get("pageInfo"));
+        let has_next_page = page_info
+            .and_then(|info| info.get("hasNextPage"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        if !has_next_page {
+            break;
+        }
+
+        after = page_info
+            .and_then(|info| info.get("endCursor"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        if after.is_none() {
+            break;
+        }
+    }
+
+    Ok(projects)
+}
+
+// Fetch the open merge requests for the project at `full_path`.
+fn fetch_merge_requests(
+    runtime: &tokio::runtime::Runtime,
+    ctx: &GitlabContext,
+    full_path: &str,
+) -> Result<Vec<MergeRequest>, Box<dyn std::error::Error>> {
+    let query = r#"
+        query MergeRequests($fullPath: ID!) {
+            project(fullPath: $fullPath) {
+                mergeRequests(state: opened, first: 50) {
+                    nodes {
+                        title
+                        state
+                        author {
+                            username
+                        }
+                    }
                 }
             }
         }
     "#;
 
-    let variables = json!({ "first": 100 });
     let payload = json!({
         "query": query,
-        "variables": variables,
+        "variables": { "fullPath": full_path },
     });
+    let url = format!("https://{}/api/graphql", ctx.host);
+    let response = runtime.block_on(post_graphql_with_retry(&ctx.client, &url, &ctx.token, &payload))?;
 
-    let client = Client::new();
-    let response = tokio::runtime::Runtime::new()?.block_on(async {
-        client
-            .post(format!("https://{}/api/graphql", gitlab_host))
-            .bearer_auth(gitlab_token)
-            .json(&payload)
-            .send()
-            .await?
-            .json::<serde_json::Value>()
-            .await
-    })?;
+    let mut merge_requests = Vec::new();
+    if let Some(nodes) = response
+        .get("data")
+        .and_then(|data| data.get("project"))
+        .and_then(|project| project.get("mergeRequests"))
+        .and_then(|mrs| mrs.get("nodes"))
+        .and_then(|nodes| nodes.as_array())
+    {
+        for node in nodes {
+            let title = node.get("title").and_then(|t| t.as_str()).unwrap_or("N/A").to_string();
+            let author = node
+                .get("author")
+                .and_then(|a| a.get("username"))
+                .and_then(|u| u.as_str())
+                .unwrap_or("N/A")
+                .to_string();
+            let state = node.get("state").and_then(|s| s.as_str()).unwrap_or("N/A").to_string();
+            merge_requests.push(MergeRequest { title, author, state });
+        }
+    }
 
-    let mut projects = Vec::new();
+    Ok(merge_requests)
+}
+
+// Fetch the most recent pipelines for the project at `full_path`.
+fn fetch_pipelines(
+    runtime: &tokio::runtime::Runtime,
+    ctx: &GitlabContext,
+    full_path: &str,
+) -> Result<Vec<Pipeline>, Box<dyn std::error::Error>> {
+    let query = r#"
+        query Pipelines($fullPath: ID!) {
+            project(fullPath: $fullPath) {
+                pipelines(first: 50) {
+                    nodes {
+                        status
+                        ref
+                        createdAt
+                    }
+                }
+            }
+        }
+    "#;
+
+    let payload = json!({
+        "query": query,
+        "variables": { "fullPath": full_path },
+    });
+    let url = format!("https://{}/api/graphql", ctx.host);
+    let response = runtime.block_on(post_graphql_with_retry(&ctx.client, &url, &ctx.token, &payload))?;
+
+    let mut pipelines = Vec::new();
     if let Some(nodes) = response
         .get("data")
-        .and_then(|data| data.get("projects"))
-        .and_then(|projects| projects.get("nodes"))
+        .and_then(|data| data.get("project"))
+        .and_then(|project| project.get("pipelines"))
+        .and_then(|pipelines| pipelines.get("nodes"))
         .and_then(|nodes| nodes.as_array())
     {
         for node in nodes {
-            let name = node.get("name").and_then(|n| n.as_str()).unwrap_or("N/A").to_string();
-            let description = node
-                .get("description")
-                .and_then(|d| d.as_str())
-                .unwrap_or("No description")
+            let status = node.get("status").and_then(|s| s.as_str()).unwrap_or("N/A").to_string();
+            let ref_name = node.get("ref").and_then(|r| r.as_str()).unwrap_or("N/A").to_string();
+            let created_at = node.get("createdAt").and_then(|c| c.as_str()).unwrap_or("N/A").to_string();
+            pipelines.push(Pipeline { status, ref_name, created_at });
+        }
+    }
+
+    Ok(pipelines)
+}
+
+// Fetch the open issues for the project at `full_path`.
+fn fetch_issues(
+    runtime: &tokio::runtime::Runtime,
+    ctx: &GitlabContext,
+    full_path: &str,
+) -> Result<Vec<Issue>, Box<dyn std::error::Error>> {
+    let query = r#"
+        query Issues($fullPath: ID!) {
+            project(fullPath: $fullPath) {
+                issues(state: opened, first: 50) {
+                    nodes {
+                        title
+                        state
+                        author {
+                            username
+                        }
+                    }
+                }
+            }
+        }
+    "#;
+
+    let payload = json!({
+        "query": query,
+        "variables": { "fullPath": full_path },
+    });
+    let url = format!("https://{}/api/graphql", ctx.host);
+    let response = runtime.block_on(post_graphql_with_retry(&ctx.client, &url, &ctx.token, &payload))?;
+
+    let mut issues = Vec::new();
+    if let Some(nodes) = response
+        .get("data")
+        .and_then(|data| data.get("project"))
+        .and_then(|project| project.get("issues"))
+        .and_then(|issues| issues.get("nodes"))
+        .and_then(|nodes| nodes.as_array())
+    {
+        for node in nodes {
+            let title = node.get("title").and_then(|t| t.as_str()).unwrap_or("N/A").to_string();
+            let author = node
+                .get("author")
+                .and_then(|a| a.get("username"))
+                .and_then(|u| u.as_str())
+                .unwrap_or("N/A")
                 .to_string();
-            let web_url = node.get("webUrl").and_then(|w| w.as_str()).unwrap_or("N/A").to_string();
-            projects.push(Project {
-                name,
-                description,
-                web_url,
-            });
+            let state = node.get("state").and_then(|s| s.as_str()).unwrap_or("N/A").to_string();
+            issues.push(Issue { title, author, state });
         }
     }
 
-    Ok(projects)
+    Ok(issues)
 }
 
 // Retrieve or prompt for the GitLab token
@@ -269,4 +1088,357 @@ fn get_gitlab_host() -> Option<String> {
             }
         }
     }
+}
+
+// Retrieve or prompt for an optional path to a PEM-encoded CA certificate,
+// for self-hosted instances behind a private/self-signed CA. Unlike the
+// token and host, this is optional: a blank answer simply means "none".
+fn get_gitlab_ssl_cert() -> Option<String> {
+    match env::var("GITLAB_SSL_CERT") {
+        Ok(path) if !path.is_empty() => Some(path),
+        _ => {
+            println!("Enter path to a custom CA certificate PEM file (leave blank to skip): ");
+            let mut path = String::new();
+            io::stdin().read_line(&mut path).unwrap();
+            let path = path.trim().to_string();
+            if path.is_empty() {
+                None
+            } else {
+                if let Err(e) = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(".env")
+                    .and_then(|mut file| writeln!(file, "GITLAB_SSL_CERT={}", path))
+                {
+                    eprintln!("Failed to save SSL cert path to .env: {}", e);
+                }
+                Some(path)
+            }
+        }
+    }
+}
+
+// Retrieve or prompt for the "skip TLS verification" toggle, for lab
+// environments where even a custom CA isn't practical. Defaults to false.
+fn get_gitlab_insecure() -> bool {
+    match env::var("GITLAB_INSECURE") {
+        Ok(flag) if !flag.is_empty() => flag.trim().eq_ignore_ascii_case("true") || flag.trim() == "1",
+        _ => {
+            println!("Skip TLS certificate verification? (y/N): ");
+            let mut answer = String::new();
+            io::stdin().read_line(&mut answer).unwrap();
+            let insecure = matches!(answer.trim().to_lowercase().as_str(), "y" | "yes");
+            if let Err(e) = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(".env")
+                .and_then(|mut file| writeln!(file, "GITLAB_INSECURE={}", insecure))
+            {
+                eprintln!("Failed to save insecure flag to .env: {}", e);
+            }
+            insecure
+        }
+    }
+}
+
+// Retrieve or prompt for which backend to browse: "gitlab" (default) or
+// "github". Checks `PROVIDER` first, then falls back to `GIT_HOST_KIND` for
+// parity with the doc comment on `Provider`. Persisted to `.env` like the
+// other settings.
+fn get_provider_kind() -> String {
+    match env::var("PROVIDER").or_else(|_| env::var("GIT_HOST_KIND")) {
+        Ok(kind) if !kind.is_empty() => kind.to_lowercase(),
+        _ => {
+            println!("Select provider - gitlab or github (leave blank for gitlab): ");
+            let mut kind = String::new();
+            io::stdin().read_line(&mut kind).unwrap();
+            let kind = kind.trim().to_lowercase();
+            let kind = if kind.is_empty() { "gitlab".to_string() } else { kind };
+            if let Err(e) = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(".env")
+                .and_then(|mut file| writeln!(file, "PROVIDER={}", kind))
+            {
+                eprintln!("Failed to save provider to .env: {}", e);
+            }
+            kind
+        }
+    }
+}
+
+// Retrieve or prompt for the GitHub token
+fn get_github_token() -> Option<String> {
+    match env::var("GITHUB_TOKEN") {
+        Ok(token) if !token.is_empty() => Some(token),
+        _ => {
+            println!("Enter your GitHub token (leave blank to exit): ");
+            let mut token = String::new();
+            io::stdin().read_line(&mut token).unwrap();
+            let token = token.trim().to_string();
+            if token.is_empty() {
+                None
+            } else {
+                if let Err(e) = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(".env")
+                    .and_then(|mut file| writeln!(file, "GITHUB_TOKEN={}", token))
+                {
+                    eprintln!("Failed to save token to .env: {}", e);
+                }
+                Some(token)
+            }
+        }
+    }
+}
+
+// Retrieve or prompt for the GitHub user/org whose repositories to list
+fn get_github_owner() -> Option<String> {
+    match env::var("GITHUB_OWNER") {
+        Ok(owner) if !owner.is_empty() => Some(owner),
+        _ => {
+            println!("Enter the GitHub user/org to list repositories for (leave blank to exit): ");
+            let mut owner = String::new();
+            io::stdin().read_line(&mut owner).unwrap();
+            let owner = owner.trim().to_string();
+            if owner.is_empty() {
+                None
+            } else {
+                if let Err(e) = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(".env")
+                    .and_then(|mut file| writeln!(file, "GITHUB_OWNER={}", owner))
+                {
+                    eprintln!("Failed to save owner to .env: {}", e);
+                }
+                Some(owner)
+            }
+        }
+    }
+}
+
+// A source of `Project`s to browse: GitLab and GitHub are the current
+// implementations, selected via `PROVIDER`/`GIT_HOST_KIND`.
+trait Provider {
+    fn fetch_projects(&self, runtime: &tokio::runtime::Runtime) -> Result<Vec<Project>, Box<dyn std::error::Error>>;
+    fn name(&self) -> &str;
+}
+
+struct GitlabProvider {
+    token: String,
+    host: String,
+    ssl_cert: Option<String>,
+    insecure: bool,
+}
+
+impl Provider for GitlabProvider {
+    fn fetch_projects(&self, runtime: &tokio::runtime::Runtime) -> Result<Vec<Project>, Box<dyn std::error::Error>> {
+        fetch_projects(
+            runtime,
+            self.token.clone(),
+            self.host.clone(),
+            self.ssl_cert.clone(),
+            self.insecure,
+        )
+    }
+
+    fn name(&self) -> &str {
+        "GitLab"
+    }
+}
+
+struct GithubProvider {
+    token: String,
+    owner: String,
+}
+
+impl Provider for GithubProvider {
+    fn fetch_projects(&self, runtime: &tokio::runtime::Runtime) -> Result<Vec<Project>, Box<dyn std::error::Error>> {
+        fetch_github_repositories(runtime, &self.token, &self.owner)
+    }
+
+    fn name(&self) -> &str {
+        "GitHub"
+    }
+}
+
+// Page size used for each GitHub REST request.
+const GITHUB_REPOS_PER_PAGE: u32 = 100;
+// Overall cap on the number of repositories fetched, mirroring
+// `PROJECTS_FETCH_CAP` for the GitLab path.
+const GITHUB_REPOS_FETCH_CAP: usize = 5000;
+
+// Look up whether `owner` is a GitHub organization or a user account, so
+// `fetch_github_repositories` can hit the endpoint that actually surfaces
+// private repos for that kind of account.
+fn is_github_org(
+    runtime: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    github_token: &str,
+    owner: &str,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let url = format!("https://api.github.com/users/{}", owner);
+    let response = runtime.block_on(
+        client
+            .get(&url)
+            .bearer_auth(github_token)
+            .header("User-Agent", "gitlab_ops")
+            .send(),
+    )?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = runtime.block_on(response.text()).unwrap_or_default();
+        return Err(format!("GitHub account lookup for {} failed with status {}: {}", owner, status, body).into());
+    }
+
+    let body = runtime.block_on(response.json::<serde_json::Value>())?;
+    Ok(body.get("type").and_then(|t| t.as_str()) == Some("Organization"))
+}
+
+// List a user's/org's repositories via the GitHub REST API and map them onto
+// the shared `Project` struct, following the `Link` header to page through
+// everything up to `GITHUB_REPOS_FETCH_CAP`. Orgs are listed via `/orgs/*`
+// (which, unlike `/users/*`, also surfaces private repos the token can see)
+// so an org owner doesn't silently end up with only its public repos.
+fn fetch_github_repositories(
+    runtime: &tokio::runtime::Runtime,
+    github_token: &str,
+    owner: &str,
+) -> Result<Vec<Project>, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let is_org = is_github_org(runtime, &client, github_token, owner)?;
+    let mut url = if is_org {
+        format!(
+            "https://api.github.com/orgs/{}/repos?type=all&per_page={}",
+            owner, GITHUB_REPOS_PER_PAGE
+        )
+    } else {
+        format!(
+            "https://api.github.com/users/{}/repos?per_page={}",
+            owner, GITHUB_REPOS_PER_PAGE
+        )
+    };
+
+    let mut projects = Vec::new();
+
+    loop {
+        let response = runtime.block_on(
+            client
+                .get(&url)
+                .bearer_auth(github_token)
+                .header("User-Agent", "gitlab_ops")
+                .send(),
+        )?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = runtime.block_on(response.text()).unwrap_or_default();
+            return Err(format!(
+                "GitHub repos request failed with status {}: {}",
+                status, body
+            )
+            .into());
+        }
+
+        let next_url = next_page_url(response.headers());
+        let body = runtime.block_on(response.json::<serde_json::Value>())?;
+
+        let repos = body
+            .as_array()
+            .ok_or("GitHub repos response was not a JSON array")?;
+
+        for repo in repos {
+            let name = repo.get("name").and_then(|n| n.as_str()).unwrap_or("N/A").to_string();
+            let description = repo
+                .get("description")
+                .and_then(|d| d.as_str())
+                .unwrap_or("No description")
+                .to_string();
+            let web_url = repo.get("html_url").and_then(|w| w.as_str()).unwrap_or("N/A").to_string();
+            let ssh_url = repo.get("ssh_url").and_then(|u| u.as_str()).unwrap_or("").to_string();
+            let http_url = repo.get("clone_url").and_then(|u| u.as_str()).unwrap_or("").to_string();
+            let full_path = repo.get("full_name").and_then(|p| p.as_str()).unwrap_or("").to_string();
+            projects.push(Project {
+                name,
+                description,
+                web_url,
+                ssh_url,
+                http_url,
+                full_path,
+            });
+        }
+
+        println!("Loaded {} repositories...", projects.len());
+
+        if projects.len() >= GITHUB_REPOS_FETCH_CAP {
+            println!(
+                "Reached the {}-repository fetch cap; remaining repositories were not loaded.",
+                GITHUB_REPOS_FETCH_CAP
+            );
+            break;
+        }
+
+        match next_url {
+            Some(next) => url = next,
+            None => break,
+        }
+    }
+
+    Ok(projects)
+}
+
+// Parse the `rel="next"` target out of a GitHub `Link` header, if present.
+fn next_page_url(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let link = headers.get(reqwest::header::LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let mut segments = part.split(';');
+        let url_part = segments.next()?.trim();
+        let is_next = segments.any(|s| s.trim() == "rel=\"next\"");
+        if !is_next {
+            return None;
+        }
+        url_part.trim_start_matches('<').trim_end_matches('>').to_string().into()
+    })
+}
+
+#[cfg(test)]
+mod fuzzy_match_tests {
+    use super::*;
+
+    #[test]
+    fn exact_prefix_match_scores_the_start_of_word_bonus() {
+        // "tool" matches "tool..." starting at index 0: one start-of-word
+        // bonus (+3) and three consecutive-match bonuses (+2 each).
+        assert_eq!(fuzzy_match_score("toolbox", "tool"), Some(9));
+    }
+
+    #[test]
+    fn mid_string_match_via_word_boundary_still_gets_the_bonus() {
+        // The "tool" in "x-tool" starts right after a non-alphanumeric char,
+        // so it still counts as a word boundary, but the two skipped chars
+        // before it ("x", "-") are penalized.
+        assert_eq!(fuzzy_match_score("x-tool", "tool"), Some(7));
+    }
+
+    #[test]
+    fn non_matching_subsequence_returns_none() {
+        assert_eq!(fuzzy_match_score("gitlab-ops", "xyz"), None);
+    }
+
+    #[test]
+    fn shorter_candidate_outranks_longer_one_with_the_same_suffix_match() {
+        // A 34-char project name that merely ends in "-tool" shouldn't rank
+        // the same as a 6-char one with the identical suffix.
+        let short = fuzzy_match_score("x-tool", "tool").unwrap();
+        let long = fuzzy_match_score("aaaaaaaaaaaaaaaaaaaaaaaaaaaaa-tool", "tool").unwrap();
+        assert!(short > long);
+    }
+
+    #[test]
+    fn fuzzy_match_positions_reports_each_matched_index() {
+        assert_eq!(fuzzy_match_positions("x-tool", "tool"), vec![2, 3, 4, 5]);
+    }
 }
\ No newline at end of file